@@ -0,0 +1,606 @@
+use darling::FromMeta;
+use proc_macro::TokenStream;
+use quote::quote;
+use std::collections::{HashSet, VecDeque};
+use syn::{AttributeArgs, ImplItemMethod, Lit, Meta, NestedMeta, Stmt, TraitItemMethod, TypePath};
+
+macro_rules! unwrap_darling {
+    ($condition:expr) => {
+        match $condition {
+            Ok(v) => v,
+            Err(e) => return TokenStream::from(e.write_errors()).into(),
+        }
+    };
+}
+
+#[derive(Default, FromMeta)]
+struct EndpointArgs {
+    #[darling(default)]
+    params: Option<TypePath>,
+    #[darling(default)]
+    query: Option<TypePath>,
+    #[darling(default = "default_name")]
+    name: String,
+    #[darling(default)]
+    raw: Option<String>,
+    #[cfg(feature = "reqwest")]
+    #[darling(default)]
+    body: Option<TypePath>,
+    #[cfg(feature = "reqwest")]
+    #[darling(default = "default_request_name")]
+    request_name: String,
+}
+
+fn default_name() -> String {
+    "endpoint".to_string()
+}
+
+#[cfg(feature = "reqwest")]
+fn default_request_name() -> String {
+    "request".to_string()
+}
+
+pub fn parse_attr(args: AttributeArgs, item: BodyItem) -> proc_macro2::TokenStream {
+    let (method, args) = split_method(args);
+    let (endpoint, endpoint_lit) = unwrap_darling!(parse_endpoint(&args));
+    let path_params = unwrap_darling!(extract_path_params(&endpoint, &endpoint_lit));
+    let args = unwrap_darling!(EndpointArgs::from_list(&args));
+    unwrap_darling!(check_params_consistency(&path_params, &args, &endpoint_lit));
+    let raw_params = unwrap_darling!(parse_raw_params(&args, &path_params, &endpoint_lit));
+    let path_params = unwrap_darling!(build_path_params(&path_params, &endpoint_lit));
+    let format_endpoint = rewrite_endpoint_for_format(&endpoint, &path_params);
+
+    let fn_endpoint = unwrap_darling!(quote_fn_endpoint(
+        &format_endpoint,
+        &path_params,
+        &raw_params,
+        &args
+    ));
+
+    #[allow(unused_mut)]
+    let mut fns = vec![fn_endpoint];
+
+    #[cfg(feature = "reqwest")]
+    fns.push(unwrap_darling!(quote_fn_request(
+        method.as_deref(),
+        &args,
+        &item
+    )));
+    #[cfg(not(feature = "reqwest"))]
+    let _ = method;
+
+    embed(fns, item)
+}
+
+/// Splits a leading bare method identifier (e.g. `POST`) off the attribute arguments, leaving
+/// the endpoint path as the first remaining argument. Parsed unconditionally since it doesn't
+/// depend on reqwest itself; it's only acted on when the `reqwest` feature is enabled.
+fn split_method(args: AttributeArgs) -> (Option<String>, AttributeArgs) {
+    match args.split_first() {
+        Some((NestedMeta::Meta(Meta::Path(path)), rest)) => {
+            let method = path
+                .get_ident()
+                .map(|ident| ident.to_string().to_uppercase());
+            (method, rest.to_vec())
+        }
+        _ => (None, args),
+    }
+}
+
+// Variant names mirror the `syn::Item*` types they wrap.
+#[allow(clippy::enum_variant_names)]
+pub enum BodyItem {
+    ItemFn(syn::ItemFn),
+    ItemImpl(syn::ItemImpl),
+    ItemTrait(syn::ItemTrait),
+}
+
+/// Requires `path` to be a bare, unqualified type name (e.g. `SearchParams`, not
+/// `types::SearchParams`), since the generated code only ever writes `&#ident` as a type
+/// reference and can't resolve a module-qualified path relative to the call site.
+fn require_ident(path: &TypePath) -> darling::Result<&syn::Ident> {
+    path.path
+        .get_ident()
+        .ok_or_else(|| darling::Error::custom("expected a non-qualified type name").with_span(path))
+}
+
+fn quote_fn_endpoint(
+    endpoint: &str,
+    path_params: &[PathParam],
+    raw_params: &HashSet<String>,
+    args: &EndpointArgs,
+) -> darling::Result<proc_macro2::TokenStream> {
+    let path_param_bindings = path_params.iter().map(|param| {
+        let ident = &param.ident;
+        let segments = &param.segments;
+        let access = quote! { params #(.#segments)* };
+        if raw_params.contains(&param.raw) {
+            quote! { let #ident = &#access; }
+        } else {
+            quote! { let #ident = ::restep::percent_encode(&#access.to_string()); }
+        }
+    });
+    let fmt_idents = path_params.iter().map(|param| param.ident.clone());
+    let fn_name = syn::Ident::from_string(&args.name).unwrap();
+
+    let base = if args.params.is_some() {
+        quote! { format!(#endpoint, #(#fmt_idents = #fmt_idents),*) }
+    } else {
+        quote! { format!(#endpoint) }
+    };
+
+    let body = if args.query.is_some() {
+        quote! {
+            let __restep_base = #base;
+            let __restep_query = query.to_query();
+            if __restep_query.is_empty() {
+                __restep_base
+            } else {
+                format!("{}?{}", __restep_base, __restep_query)
+            }
+        }
+    } else {
+        base
+    };
+
+    let tokens = match (&args.params, &args.query) {
+        (Some(params), Some(query)) => {
+            let params_ty = require_ident(params)?;
+            let query_ty = require_ident(query)?;
+            quote! {
+                fn #fn_name(params: &#params_ty, query: &#query_ty) -> String {
+                    #(#path_param_bindings)*
+                    #body
+                }
+            }
+        }
+        (Some(params), None) => {
+            let params_ty = require_ident(params)?;
+            quote! {
+                fn #fn_name(params: &#params_ty) -> String {
+                    #(#path_param_bindings)*
+                    #body
+                }
+            }
+        }
+        (None, Some(query)) => {
+            let query_ty = require_ident(query)?;
+            quote! {
+                fn #fn_name(query: &#query_ty) -> String {
+                    #body
+                }
+            }
+        }
+        (None, None) => {
+            quote! {
+                fn #fn_name() -> String {
+                    #body
+                }
+            }
+        }
+    };
+    Ok(tokens)
+}
+
+/// Generates a `reqwest`-backed request builder alongside `endpoint()`, e.g.
+/// `fn request(client: &reqwest::Client, base: &str, params: &P, body: &B) -> reqwest::RequestBuilder`.
+/// The method defaults to `GET` when no method identifier was given in the attribute.
+#[cfg(feature = "reqwest")]
+fn quote_fn_request(
+    method: Option<&str>,
+    args: &EndpointArgs,
+    item: &BodyItem,
+) -> darling::Result<proc_macro2::TokenStream> {
+    let fn_name = syn::Ident::from_string(&args.name).unwrap();
+    let request_fn_name = syn::Ident::from_string(&args.request_name).unwrap();
+    let client_method = syn::Ident::from_string(&method.unwrap_or("GET").to_lowercase()).unwrap();
+
+    let mut fn_args = vec![quote! { client: &reqwest::Client }, quote! { base: &str }];
+    let mut call_args = Vec::new();
+
+    if let Some(params) = &args.params {
+        let params_ty = require_ident(params)?;
+        fn_args.push(quote! { params: &#params_ty });
+        call_args.push(quote! { params });
+    }
+    if let Some(query) = &args.query {
+        let query_ty = require_ident(query)?;
+        fn_args.push(quote! { query: &#query_ty });
+        call_args.push(quote! { query });
+    }
+
+    let attach_body = if let Some(body) = &args.body {
+        let body_ty = require_ident(body)?;
+        fn_args.push(quote! { body: &#body_ty });
+        quote! { builder.json(body) }
+    } else {
+        quote! { builder }
+    };
+
+    // `endpoint()` and `request()` are embedded as siblings: nested fns inside the same
+    // block for `ItemFn`, but impl/trait methods for `ItemImpl`/`ItemTrait`, where a bare
+    // call doesn't resolve and needs `Self::` instead.
+    let call_endpoint = match item {
+        BodyItem::ItemFn(_) => quote! { #fn_name(#(#call_args),*) },
+        BodyItem::ItemImpl(_) | BodyItem::ItemTrait(_) => {
+            quote! { Self::#fn_name(#(#call_args),*) }
+        }
+    };
+
+    Ok(quote! {
+        fn #request_fn_name(#(#fn_args),*) -> reqwest::RequestBuilder {
+            let url = format!("{}{}", base, #call_endpoint);
+            let builder = client.#client_method(url);
+            #attach_body
+        }
+    })
+}
+
+/// Embeds one or more generated function items (`endpoint()`, and with the `reqwest` feature
+/// `request()` alongside it) into the annotated item. Each function is parsed and inserted
+/// individually, since the `TokenStream`s can't be parsed as a single item once there's more
+/// than one function in them.
+fn embed(fns: Vec<proc_macro2::TokenStream>, item: BodyItem) -> proc_macro2::TokenStream {
+    match item {
+        BodyItem::ItemFn(mut item) => {
+            // e.g.
+            // fn something() {
+            //     fn endpoint() { ... }
+            // }
+            for (i, fn_endpoint) in fns.into_iter().enumerate() {
+                let fn_endpoint = syn::parse::<Stmt>(fn_endpoint.into()).unwrap();
+                item.block.stmts.insert(i, fn_endpoint);
+            }
+            quote!(#item)
+        }
+        BodyItem::ItemImpl(mut item) => {
+            // e.g)
+            // impl Something {
+            //     fn endpoint() { ... }
+            // }
+            for fn_endpoint in fns {
+                let fn_endpoint = syn::parse::<ImplItemMethod>(fn_endpoint.into()).unwrap();
+                item.items.push(syn::ImplItem::Method(fn_endpoint));
+            }
+            quote!(#item)
+        }
+        BodyItem::ItemTrait(mut item) => {
+            // e.g)
+            // trait Something {
+            //     fn endpoint() { ... } // provided, every implementor gets it for free
+            // }
+            for fn_endpoint in fns {
+                let fn_endpoint = syn::parse::<TraitItemMethod>(fn_endpoint.into()).unwrap();
+                item.items.push(syn::TraitItem::Method(fn_endpoint));
+            }
+            quote!(#item)
+        }
+    }
+}
+
+fn parse_endpoint(args: &AttributeArgs) -> darling::Result<(String, syn::LitStr)> {
+    let first = args
+        .first()
+        .ok_or_else(|| darling::Error::missing_field("endpoint"))?;
+    match first {
+        NestedMeta::Lit(Lit::Str(lit)) => Ok((lit.value(), lit.clone())),
+        _ => {
+            Err(darling::Error::custom("expected a string literal endpoint path").with_span(first))
+        }
+    }
+}
+
+/// Extracts the `{param}` names from an endpoint path, rejecting anything the macro can
+/// statically catch: an empty `{}`, an unterminated or unmatched brace, and duplicate names.
+/// Errors carry `lit`'s span so they point at the attribute rather than at generated code.
+fn extract_path_params(endpoint: &str, lit: &syn::LitStr) -> darling::Result<Vec<String>> {
+    let mut result = Vec::new();
+    let mut seen = HashSet::new();
+    let mut current = VecDeque::new();
+    let mut is_dyn = false;
+    for char in endpoint.chars() {
+        match char {
+            '{' if is_dyn => {
+                return Err(darling::Error::custom(format!(
+                    "nested `{{` in endpoint path `{}`",
+                    endpoint
+                ))
+                .with_span(lit));
+            }
+            '{' => {
+                is_dyn = true;
+            }
+            '}' if !is_dyn => {
+                return Err(darling::Error::custom(format!(
+                    "unmatched `}}` in endpoint path `{}`",
+                    endpoint
+                ))
+                .with_span(lit));
+            }
+            '}' => {
+                is_dyn = false;
+                let name: String = current.drain(0..).collect();
+                if name.is_empty() {
+                    return Err(darling::Error::custom(format!(
+                        "empty path parameter `{{}}` in endpoint path `{}`",
+                        endpoint
+                    ))
+                    .with_span(lit));
+                }
+                if !seen.insert(name.clone()) {
+                    return Err(darling::Error::custom(format!(
+                        "duplicate path parameter `{{{}}}` in endpoint path `{}`",
+                        name, endpoint
+                    ))
+                    .with_span(lit));
+                }
+                result.push(name);
+            }
+            _ if is_dyn => {
+                current.push_back(char);
+            }
+            _ => {}
+        }
+    }
+    if is_dyn {
+        return Err(darling::Error::custom(format!(
+            "unterminated `{{` in endpoint path `{}`",
+            endpoint
+        ))
+        .with_span(lit));
+    }
+    Ok(result)
+}
+
+/// Cross-checks the path params found in the endpoint against the `params` attribute: a
+/// `{param}` placeholder with no `params = "..."` attribute (or vice versa) can't work, since
+/// the generated code would reference a `params` binding that doesn't exist (or go unused).
+fn check_params_consistency(
+    path_params: &[String],
+    args: &EndpointArgs,
+    lit: &syn::LitStr,
+) -> darling::Result<()> {
+    if !path_params.is_empty() && args.params.is_none() {
+        return Err(darling::Error::custom(format!(
+            "endpoint path references {} but no `params = \"...\"` attribute was given",
+            path_params
+                .iter()
+                .map(|p| format!("`{{{}}}`", p))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+        .with_span(lit));
+    }
+    if path_params.is_empty() && args.params.is_some() {
+        return Err(darling::Error::custom(
+            "`params` attribute was given but the endpoint path has no `{param}` placeholders",
+        )
+        .with_span(lit));
+    }
+    Ok(())
+}
+
+/// Parses the `raw = "param_a, param_b"` escape hatch that opts specific path parameters out of
+/// percent-encoding, for callers intentionally passing a pre-encoded or multi-segment value.
+fn parse_raw_params(
+    args: &EndpointArgs,
+    path_params: &[String],
+    lit: &syn::LitStr,
+) -> darling::Result<HashSet<String>> {
+    let raw: HashSet<String> = match &args.raw {
+        Some(raw) => raw.split(',').map(|p| p.trim().to_string()).collect(),
+        None => HashSet::new(),
+    };
+    for name in &raw {
+        if !path_params.contains(name) {
+            return Err(darling::Error::custom(format!(
+                "`raw` lists `{}` but the endpoint path has no `{{{}}}` placeholder",
+                name, name
+            ))
+            .with_span(lit));
+        }
+    }
+    Ok(raw)
+}
+
+/// A `{param}` or `{nested.field}` path parameter, resolved into the pieces needed to generate
+/// code: the original dotted name, a sanitized binding identifier safe to use as a local `let`
+/// (dots aren't valid in identifiers), and the field-access path it reads from `params`.
+struct PathParam {
+    raw: String,
+    ident: syn::Ident,
+    segments: Vec<syn::Ident>,
+}
+
+/// Resolves each raw `{param}`/`{nested.field}` name into a [`PathParam`], sanitizing dotted
+/// names into a single binding identifier (e.g. `customer.id` -> `__restep_customer_id`). The
+/// `__restep_` prefix is a reserved token so a binding can't shadow the generated function's own
+/// `params`/`query` arguments (e.g. for a `{params}` or `{query}` placeholder). The prefix alone
+/// doesn't separate `customer_id` from `customer.id` (both become `__restep_customer_id`), so two
+/// different raw names that sanitize to the same identifier (e.g. `a.b` and `a_b`) are rejected.
+fn build_path_params(names: &[String], lit: &syn::LitStr) -> darling::Result<Vec<PathParam>> {
+    let mut seen = HashSet::new();
+    let mut result = Vec::with_capacity(names.len());
+    for raw in names {
+        let mut segments = Vec::new();
+        for segment in raw.split('.') {
+            let ident = syn::Ident::from_string(segment).map_err(|_| {
+                darling::Error::custom(format!(
+                    "path parameter `{{{}}}` has an invalid segment `{}`; each dot-separated segment must be a non-empty identifier",
+                    raw, segment
+                ))
+                .with_span(lit)
+            })?;
+            segments.push(ident);
+        }
+        let sanitized = format!("__restep_{}", raw.replace('.', "_"));
+        if !seen.insert(sanitized.clone()) {
+            return Err(darling::Error::custom(format!(
+                "path parameter `{{{}}}` collides with another parameter once sanitized to `{}`; rename one of them",
+                raw, sanitized
+            ))
+            .with_span(lit));
+        }
+        let ident = syn::Ident::from_string(&sanitized).unwrap();
+        result.push(PathParam {
+            raw: raw.clone(),
+            ident,
+            segments,
+        });
+    }
+    Ok(result)
+}
+
+/// Rewrites `{raw.name}` placeholders in the endpoint path to `{sanitized_ident}` so the string
+/// can be handed to `format!` as a literal with valid Rust named arguments; `format!` doesn't
+/// allow `.` in `{name}` placeholders even though the original path syntax does.
+fn rewrite_endpoint_for_format(endpoint: &str, path_params: &[PathParam]) -> String {
+    let mut out = String::with_capacity(endpoint.len());
+    let mut is_dyn = false;
+    let mut idx = 0;
+    for char in endpoint.chars() {
+        match char {
+            '{' if !is_dyn => {
+                is_dyn = true;
+                out.push('{');
+            }
+            '}' if is_dyn => {
+                is_dyn = false;
+                out.push_str(&path_params[idx].ident.to_string());
+                idx += 1;
+                out.push('}');
+            }
+            _ if is_dyn => {}
+            _ => out.push(char),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit(s: &str) -> syn::LitStr {
+        syn::LitStr::new(s, proc_macro2::Span::call_site())
+    }
+
+    #[test]
+    fn test_parse_endpoint() {
+        assert_eq!(
+            extract_path_params("/static", &lit("/static")).unwrap(),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            extract_path_params("/static/{id}", &lit("/static/{id}")).unwrap(),
+            vec!["id"]
+        );
+        assert_eq!(
+            extract_path_params("/static/{id}/{second}", &lit("/static/{id}/{second}")).unwrap(),
+            vec!["id", "second"]
+        );
+    }
+
+    #[test]
+    fn test_parse_endpoint_rejects_empty_param() {
+        assert!(extract_path_params("/static/{}", &lit("/static/{}")).is_err());
+    }
+
+    #[test]
+    fn test_parse_endpoint_rejects_duplicate_param() {
+        assert!(extract_path_params("/static/{id}/{id}", &lit("/static/{id}/{id}")).is_err());
+    }
+
+    #[test]
+    fn test_parse_endpoint_rejects_unterminated_brace() {
+        assert!(extract_path_params("/static/{id", &lit("/static/{id")).is_err());
+    }
+
+    #[test]
+    fn test_parse_endpoint_rejects_unmatched_closing_brace() {
+        assert!(extract_path_params("/static/id}", &lit("/static/id}")).is_err());
+    }
+
+    #[test]
+    fn test_build_path_params_sanitizes_nested_fields() {
+        let names = vec!["customer.id".to_string(), "item.sku".to_string()];
+        let params = build_path_params(&names, &lit("")).unwrap();
+        assert_eq!(params[0].ident.to_string(), "__restep_customer_id");
+        assert_eq!(
+            params[0]
+                .segments
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>(),
+            vec!["customer", "id"]
+        );
+        assert_eq!(params[1].ident.to_string(), "__restep_item_sku");
+    }
+
+    #[test]
+    fn test_build_path_params_rejects_sanitized_collision() {
+        let names = vec!["a.b".to_string(), "a_b".to_string()];
+        assert!(build_path_params(&names, &lit("")).is_err());
+    }
+
+    #[test]
+    fn test_build_path_params_rejects_empty_segment() {
+        let names = vec!["a..b".to_string()];
+        assert!(build_path_params(&names, &lit("")).is_err());
+    }
+
+    #[test]
+    fn test_rewrite_endpoint_for_format() {
+        let names = vec!["customer.id".to_string(), "item.sku".to_string()];
+        let params = build_path_params(&names, &lit("")).unwrap();
+        assert_eq!(
+            rewrite_endpoint_for_format("/orders/{customer.id}/items/{item.sku}", &params),
+            "/orders/{__restep_customer_id}/items/{__restep_item_sku}"
+        );
+    }
+
+    #[test]
+    fn test_require_ident_rejects_qualified_path() {
+        let path: TypePath = syn::parse_str("types::SearchParams").unwrap();
+        assert!(require_ident(&path).is_err());
+    }
+
+    #[cfg(feature = "reqwest")]
+    fn reqwest_test_args() -> EndpointArgs {
+        EndpointArgs {
+            params: None,
+            query: None,
+            name: "endpoint".to_string(),
+            raw: None,
+            body: None,
+            request_name: "request".to_string(),
+        }
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_quote_fn_request_self_qualifies_for_impl() {
+        let item = BodyItem::ItemImpl(syn::parse_str("impl Foo {}").unwrap());
+        let tokens = quote_fn_request(None, &reqwest_test_args(), &item).unwrap();
+        assert!(tokens.to_string().contains("Self :: endpoint"));
+        syn::parse2::<ImplItemMethod>(tokens).unwrap();
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_quote_fn_request_self_qualifies_for_trait() {
+        let item = BodyItem::ItemTrait(syn::parse_str("trait Foo {}").unwrap());
+        let tokens = quote_fn_request(None, &reqwest_test_args(), &item).unwrap();
+        assert!(tokens.to_string().contains("Self :: endpoint"));
+        syn::parse2::<TraitItemMethod>(tokens).unwrap();
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_quote_fn_request_bare_call_for_item_fn() {
+        let item = BodyItem::ItemFn(syn::parse_str("fn wrapper() {}").unwrap());
+        let tokens = quote_fn_request(None, &reqwest_test_args(), &item).unwrap();
+        assert!(!tokens.to_string().contains("Self ::"));
+        syn::parse2::<Stmt>(tokens).unwrap();
+    }
+}
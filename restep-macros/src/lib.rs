@@ -0,0 +1,67 @@
+mod endpoint;
+
+use crate::endpoint::parse_attr;
+use endpoint::BodyItem;
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, AttributeArgs};
+
+///
+/// Creates a function that returns the specified path.
+///
+/// # Syntax
+/// `#[endpoint("path"[, attributes])]`
+///
+/// # Attributes
+/// - `path`: endpoint. If an embedded variable is enclosed in braces, the variable must be a field of `params`.
+///   A dotted `{nested.field}` reaches into a sub-struct field of `params`.
+/// - `name = "function name"`: Name for auto-generated function. Default is `endpoint`
+/// - `params = "argument type"`: Argument type for auto-generated function.
+/// - `query = "argument type"`: Query string argument type. The type must implement `restep::ToQuery`;
+///   the generated function appends `?` followed by `ToQuery::to_query()` when it is non-empty.
+/// - `raw = "field_a, field_b"`: Comma-separated list of `params` fields to exclude from
+///   percent-encoding when interpolated into the path. Every other `{param}` is always
+///   percent-encoded via `restep::percent_encode`.
+///
+/// # The `reqwest` feature
+/// When the `reqwest` feature is enabled, `#[endpoint(METHOD, "path", ...)]` accepts a leading
+/// HTTP method (`GET`, `POST`, `PUT`, `PATCH`, `DELETE`, defaulting to `GET` when omitted) and a
+/// `body = "argument type"` attribute, and in addition to `endpoint()` generates a `request()`
+/// function (name configurable via `request_name = "..."`) with the signature
+/// `fn request(client: &reqwest::Client, base: &str, ...) -> reqwest::RequestBuilder`, which
+/// selects the right `reqwest::Client` method, joins `base` with `endpoint(...)`, and attaches
+/// `.json(body)` when a body type was given.
+///
+#[proc_macro_attribute]
+pub fn endpoint(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = match parse_item(item) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    parse_attr(parse_macro_input!(attr as AttributeArgs), item).into()
+}
+
+macro_rules! parses {
+    ($item:expr, $(($synTy:path as $bodyTy:path)),+$(,)*) => {
+        {
+            let mut err;
+            $(
+                let result = syn::parse::<$synTy>($item.clone()).map($bodyTy);
+                match result {
+                    Ok(v) => return Ok(v),
+                    #[allow(unused_assignments)]
+                    Err(e) => err = e,
+                }
+            )*
+            Err(err)
+        }
+    };
+}
+
+fn parse_item(item: TokenStream) -> syn::Result<BodyItem> {
+    parses!(
+        item,
+        (syn::ItemImpl as BodyItem::ItemImpl),
+        (syn::ItemTrait as BodyItem::ItemTrait),
+        (syn::ItemFn as BodyItem::ItemFn),
+    )
+}
@@ -34,6 +34,60 @@
 //! assert_eq!(dynamic_route(), "/customers/1");
 //! ```
 //!
+//! Path parameter values are always percent-encoded before interpolation, so a value containing
+//! e.g. `/`, `?`, `#`, or a space can't produce a malformed or path-traversing URL:
+//! ```
+//! use restep::endpoint;
+//!
+//! struct PathParameters {
+//!     name: String,
+//! }
+//!
+//! #[endpoint("/customers/{name}", params = "PathParameters")]
+//! fn encoded_route() -> String {
+//!     let params = PathParameters { name: "a/b c".to_string() };
+//!     endpoint(&params)
+//! }
+//! assert_eq!(encoded_route(), "/customers/a%2Fb%20c");
+//! ```
+//!
+//! Use `raw = "field_a, field_b"` to opt specific parameters out of encoding, e.g. when a caller
+//! intentionally passes a pre-encoded or multi-segment path:
+//! ```
+//! use restep::endpoint;
+//!
+//! struct PathParameters {
+//!     path: String,
+//! }
+//!
+//! #[endpoint("/files/{path}", params = "PathParameters", raw = "path")]
+//! fn raw_route() -> String {
+//!     let params = PathParameters { path: "a/b".to_string() };
+//!     endpoint(&params)
+//! }
+//! assert_eq!(raw_route(), "/files/a/b");
+//! ```
+//!
+//! A `{nested.field}` path parameter reaches into a sub-struct of `params`:
+//! ```
+//! use restep::endpoint;
+//!
+//! struct Customer {
+//!     id: i32,
+//! }
+//!
+//! struct PathParameters {
+//!     customer: Customer,
+//! }
+//!
+//! #[endpoint("/customers/{customer.id}", params = "PathParameters")]
+//! fn nested_route() -> String {
+//!     let params = PathParameters { customer: Customer { id: 1 } };
+//!     endpoint(&params)
+//! }
+//! assert_eq!(nested_route(), "/customers/1");
+//! ```
+//!
 //! ## impl
 //! ```
 //! use restep::endpoint;
@@ -51,6 +105,59 @@
 //! assert_eq!(APIClient::path(), "/customers");
 //! ```
 //!
+//! ## trait
+//! Applying `#[endpoint]` to a trait injects `endpoint()` as a provided method, so every
+//! implementor gets it for free without re-annotating.
+//! ```
+//! use restep::endpoint;
+//!
+//! struct PathParameters {
+//!     id: i32,
+//! }
+//!
+//! #[endpoint("/customers/{id}", params = "PathParameters")]
+//! trait CustomerApi {}
+//!
+//! struct APIClient;
+//! impl CustomerApi for APIClient {}
+//!
+//! let params = PathParameters { id: 1 };
+//! assert_eq!(APIClient::endpoint(&params), "/customers/1");
+//! ```
+//!
+//! ## Query Parameters
+//! ```
+//! use restep::{endpoint, ToQuery};
+//!
+//! struct SearchParams {
+//!     name: Option<String>,
+//! }
+//!
+//! impl ToQuery for SearchParams {
+//!     fn to_query(&self) -> String {
+//!         match &self.name {
+//!             Some(name) => format!("name={}", name),
+//!             None => String::new(),
+//!         }
+//!     }
+//! }
+//!
+//! #[endpoint("/customers", query = "SearchParams")]
+//! fn with_query(query: &SearchParams) -> String {
+//!     // You can use `fn endpoint(query: &SearchParams) -> String` in this function.
+//!     endpoint(query)
+//! }
+//!
+//! let query = SearchParams { name: Some("foo".to_string()) };
+//! assert_eq!(with_query(&query), "/customers?name=foo");
+//! ```
+//!
+//! ## Request Builder (`reqwest` feature)
+//! With the `reqwest` feature enabled, `#[endpoint(POST, "/customers", body = "CreateCustomer")]`
+//! generates a `request()` function alongside `endpoint()`; see `request_builder_doctest` in this
+//! crate's source for a compiled example (kept out of these crate docs since the example only
+//! compiles with the feature on, and these docs always compile).
+//!
 //! # Examples
 //!
 //! ## RealWorld
@@ -86,54 +193,70 @@
 //! }
 //! ```
 
-mod endpoint;
-
-use crate::endpoint::parse_attr;
-use endpoint::BodyItem;
-use proc_macro::TokenStream;
-use syn::{parse_macro_input, AttributeArgs};
-
-///
 /// Creates a function that returns the specified path.
 ///
-/// # Syntax
-/// `#[endpoint("path"[, attributes])]`
-///
-/// # Attributes
-/// - `path`: endpoint. If an embedded variable is enclosed in braces, the variable must be a field of `params`.
-/// - `name = "function name"`: Name for auto-generated function. Default is `endpoint`
-/// - `params = "argument type"`: Argument type for auto-generated function.
+/// See the [`restep_macros`] crate for the full attribute syntax (`params`, `query`, `raw`, the
+/// `reqwest` feature, ...); it's documented there because `#[proc_macro_attribute]` items must
+/// live in their own `proc-macro = true` crate and can't be re-documented on a `pub use`.
+pub use restep_macros::endpoint;
+
+/// Converts a value into a URL query string (without the leading `?`).
 ///
-#[proc_macro_attribute]
-pub fn endpoint(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let item = match parse_item(item) {
-        Ok(v) => v,
-        Err(e) => return TokenStream::from(e.to_compile_error()),
-    };
-    parse_attr(parse_macro_input!(attr as AttributeArgs), item).into()
+/// The `query` attribute of [`endpoint`] calls this trait instead of reflecting over the
+/// query type's fields, since a proc-macro can't see the fields of an externally defined
+/// struct. Implementors should percent-encode values themselves and skip fields that are
+/// absent (e.g. `None`), joining the remaining `key=value` pairs with `&`.
+pub trait ToQuery {
+    fn to_query(&self) -> String;
 }
 
-macro_rules! parses {
-    ($item:expr, $(($synTy:path as $bodyTy:path)),+$(,)*) => {
-        {
-            let mut err;
-            $(
-                let result = syn::parse::<$synTy>($item.clone()).map($bodyTy);
-                match result {
-                    Ok(v) => return Ok(v),
-                    #[allow(unused_assignments)]
-                    Err(e) => err = e,
-                }
-            )*
-            Err(err)
+/// Percent-encodes `value` outside the unreserved set `A-Za-z0-9-._~`, per
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-2.3).
+///
+/// The `endpoint()` function generated by [`endpoint`] calls this on every interpolated path
+/// parameter so a value containing e.g. `/`, `?`, `#`, or a space can't produce a malformed or
+/// path-traversing URL. Use the `raw = "field_a, field_b"` attribute to opt specific parameters
+/// out of this.
+pub fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char);
+            }
+            _ => {
+                encoded.push('%');
+                encoded.push_str(&format!("{:02X}", byte));
+            }
         }
-    };
+    }
+    encoded
 }
 
-fn parse_item(item: TokenStream) -> syn::Result<BodyItem> {
-    parses!(
-        item,
-        (syn::ItemImpl as BodyItem::ItemImpl),
-        (syn::ItemFn as BodyItem::ItemFn),
-    )
-}
+/// ```
+/// use restep::endpoint;
+///
+/// #[derive(serde::Serialize)]
+/// struct CreateCustomer {
+///     name: String,
+/// }
+///
+/// // `request()` is generated alongside `endpoint()` and selects `client.post(..)`, joining
+/// // `base` with `endpoint()` and attaching `.json(body)`; like `endpoint()`, it's nested inside
+/// // this function and must be called from here, so the wrapper takes its arguments instead.
+/// #[endpoint(POST, "/customers", body = "CreateCustomer")]
+/// fn create_customer(
+///     client: &reqwest::Client,
+///     base: &str,
+///     body: &CreateCustomer,
+/// ) -> reqwest::RequestBuilder {
+///     request(client, base, body)
+/// }
+///
+/// let client = reqwest::Client::new();
+/// let body = CreateCustomer { name: "foo".to_string() };
+/// let _builder = create_customer(&client, "https://api.example.com", &body);
+/// ```
+#[cfg(feature = "reqwest")]
+#[allow(dead_code)]
+fn request_builder_doctest() {}